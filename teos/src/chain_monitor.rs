@@ -7,9 +7,10 @@ use std::time;
 use tokio::time::timeout;
 use triggered::Listener;
 
+use bitcoin::network::constants::Network;
 use lightning::chain;
-use lightning_block_sync::poll::{ChainTip, Poll, ValidatedBlockHeader};
-use lightning_block_sync::{Cache, SpvClient};
+use lightning_block_sync::poll::{ChainPoller, ChainTip, ValidatedBlockHeader};
+use lightning_block_sync::{BlockSource, BlockSourceErrorKind, Cache, SpvClient};
 
 use crate::dbm::DBM;
 
@@ -17,94 +18,250 @@ use crate::dbm::DBM;
 ///
 /// Takes care of polling `bitcoind` for new tips and hand it to subscribers.
 /// It is mainly a wrapper around [chain::Listen] that provides some logging.
-pub struct ChainMonitor<'a, P, C, L>
+pub struct ChainMonitor<'a, C, L>
 where
-    P: Poll,
     C: Cache,
-    L: Deref,
+    L: Deref + Copy,
     L::Target: chain::Listen,
 {
-    /// A bitcoin client to poll best tips from.
-    spv_client: SpvClient<'a, P, C, L>,
-    /// The lat known block header by the [ChainMonitor].
+    /// The ordered list of block sources to poll best tips from. Every poll always starts from
+    /// index 0, rotating to the next one in the list on a connection error so a single
+    /// unreachable `bitcoind` doesn't stall monitoring; this also means a recovered, higher
+    /// priority source is picked back up on its very next poll instead of being stuck behind
+    /// whichever fallback last succeeded. Each poll builds a fresh [SpvClient] seeded from
+    /// [last_known_block_header](Self::last_known_block_header), sharing the same
+    /// [header_cache](Self::header_cache) and [chain_listener](Self::chain_listener) across every
+    /// source, so whichever one answers picks up exactly where the tower left off instead of
+    /// comparing against a tip of its own that's gone stale and replaying blocks a different
+    /// source already delivered.
+    block_sources: Vec<&'a dyn BlockSource>,
+    /// The index, within [block_sources](Self::block_sources), of the last source that answered a
+    /// poll successfully.
+    active_source: usize,
+    /// The network the monitored chain belongs to.
+    network: Network,
+    /// The block header cache shared by every source in [block_sources](Self::block_sources).
+    header_cache: &'a mut C,
+    /// The listener shared by every source in [block_sources](Self::block_sources).
+    chain_listener: L,
+    /// The last known block header by the [ChainMonitor].
     last_known_block_header: ValidatedBlockHeader,
     /// A [DBM] (database manager) instance. Used to persist block data into disk.
     dbm: Arc<Mutex<DBM>>,
-    /// The time between polls.
+    /// The time between polls while [healthy](Self::healthy).
     polling_delta: time::Duration,
+    /// The time to sleep before the next poll. Equal to [polling_delta](Self::polling_delta)
+    /// while healthy, doubling (up to [MAX_BACKOFF](Self::MAX_BACKOFF)) on every consecutive
+    /// failed poll so a downed `bitcoind` doesn't get hammered with identical requests.
+    next_sleep: time::Duration,
+    /// Whether the last poll managed to reach one of [block_sources](Self::block_sources).
+    healthy: bool,
     /// A signal from the main thread indicating the tower is shuting down.
     shutdown_signal: Listener,
 }
 
-impl<'a, P, C, L> ChainMonitor<'a, P, C, L>
+impl<'a, C, L> ChainMonitor<'a, C, L>
 where
-    P: Poll,
     C: Cache,
-    L: Deref,
+    L: Deref + Copy,
     L::Target: chain::Listen,
 {
+    /// The maximum time to back off to between polls of an unhealthy `bitcoind`.
+    const MAX_BACKOFF: time::Duration = time::Duration::from_secs(300);
+
     /// Creates a new [ChainMonitor] instance.
+    ///
+    /// `block_sources` must hold at least one source (e.g. a local `bitcoind` plus a remote
+    /// fallback). They are tried in order, starting from the first, on every poll, all sharing
+    /// `header_cache` and `chain_listener` so a source that hasn't answered in a while is never
+    /// probed against a tip of its own.
     pub async fn new(
-        spv_client: SpvClient<'a, P, C, L>,
+        block_sources: Vec<&'a dyn BlockSource>,
+        network: Network,
+        header_cache: &'a mut C,
+        chain_listener: L,
         last_known_block_header: ValidatedBlockHeader,
         dbm: Arc<Mutex<DBM>>,
         polling_delta_sec: u64,
         shutdown_signal: Listener,
-    ) -> ChainMonitor<'a, P, C, L> {
+    ) -> ChainMonitor<'a, C, L> {
+        assert!(
+            !block_sources.is_empty(),
+            "ChainMonitor needs at least one block source to poll from"
+        );
+        let polling_delta = time::Duration::from_secs(polling_delta_sec);
         ChainMonitor {
-            spv_client,
+            block_sources,
+            active_source: 0,
+            network,
+            header_cache,
+            chain_listener,
             last_known_block_header,
             dbm,
-            polling_delta: time::Duration::from_secs(polling_delta_sec),
+            polling_delta,
+            next_sleep: polling_delta,
+            healthy: true,
             shutdown_signal,
         }
     }
 
+    /// Whether the tower is currently able to reach at least one of its configured block sources.
+    pub fn is_healthy(&self) -> bool {
+        self.healthy
+    }
+
+    /// Brings the [ChainMonitor] listeners up to date with the chain before [monitor_chain](Self::monitor_chain)
+    /// starts its regular polling loop.
+    ///
+    /// On startup `last_known_block_header` may be several blocks (or a reorg) behind the current
+    /// best tip, e.g. because the tower was offline for a while. This doesn't need any extra logic
+    /// on our end though: [SpvClient::poll_best_tip] already finds the common ancestor between the
+    /// persisted tip and the current one and replays every block (connects and disconnects) in
+    /// between, the same way lightning-block-sync's `synchronize_listeners` does, and
+    /// [poll_best_tip](Self::poll_best_tip) only persists the new tip to the [DBM] once the
+    /// listeners have been brought forward. So a single call to
+    /// [poll_best_tip](Self::poll_best_tip) is already enough to catch up; this method exists to
+    /// give that first, possibly long-running, poll its own descriptive log lines.
+    pub async fn init(&mut self) {
+        log::info!(
+            "Starting up. Syncing listeners from block {}",
+            self.last_known_block_header.header.block_hash()
+        );
+        self.poll_best_tip().await;
+        if self.healthy {
+            log::info!(
+                "Listeners synced. Tip: {}",
+                self.last_known_block_header.header.block_hash()
+            );
+        } else {
+            log::warn!(
+                "Failed to sync listeners at startup (could not reach any configured source). Will retry on the next poll"
+            );
+        }
+    }
+
     /// Polls the best chain tip from bitcoind. Serves the data to its listeners (through [chain::Listen]) and logs data about the polled tips.
+    ///
+    /// Every call probes [block_sources](Self::block_sources) starting from index 0, rotating to
+    /// the next configured source on a transient connection error (e.g. `bitcoind` being
+    /// unreachable) until one answers or all of them have been tried for this poll. Each source is
+    /// probed through a freshly built [SpvClient], seeded with
+    /// [last_known_block_header](Self::last_known_block_header) and sharing
+    /// [header_cache](Self::header_cache)/[chain_listener](Self::chain_listener) with every other
+    /// source, rather than a long-lived client per source: that way a source is never compared
+    /// against a tip of its own that's gone stale while a different source was answering polls,
+    /// which would otherwise make it replay blocks the listener already received. A validation
+    /// error, on the other hand, is not solved by switching sources, so it's just logged and the
+    /// current tip is kept. Either way, [healthy](Self::healthy) and
+    /// [next_sleep](Self::next_sleep) are updated to reflect whether the poll could reach a
+    /// source at all.
     pub async fn poll_best_tip(&mut self) {
-        match self.spv_client.poll_best_tip().await {
-            Ok((chain_tip, _)) => match chain_tip {
-                ChainTip::Common => log::debug!("No new best tip found"),
-
-                ChainTip::Better(new_best) => {
-                    log::debug!("Updating best tip: {}", new_best.header.block_hash());
-                    self.last_known_block_header = new_best;
-                    self.dbm
-                        .lock()
-                        .unwrap()
-                        .store_last_known_block(&new_best.header.block_hash())
-                        .unwrap();
-                }
-                ChainTip::Worse(worse) => {
-                    // This would happen both if a block has less chainwork than the previous one, or if it has the same chainwork
-                    // but it forks from the parent. In both cases, it'll be detected as a reorg once (if) the new chain grows past
-                    // the current tip.
-                    log::warn!("Worse tip found: {:?}", worse.header.block_hash());
-
-                    if worse.chainwork == self.last_known_block_header.chainwork {
-                        log::warn!("New tip has the same work as the previous one")
-                    } else {
-                        log::warn!("New tip has less work than the previous one")
+        for source in 0..self.block_sources.len() {
+            let poller = ChainPoller::new(self.block_sources[source], self.network);
+            let mut spv_client = SpvClient::new(
+                self.last_known_block_header,
+                poller,
+                self.header_cache,
+                self.chain_listener,
+            );
+            match spv_client.poll_best_tip().await {
+                Ok((chain_tip, blocks_connected)) => {
+                    if source < self.active_source {
+                        log::warn!("Source {} has recovered. Switching back to it", source);
+                    } else if source > self.active_source {
+                        log::info!("Now polling from source {}", source);
                     }
+                    self.active_source = source;
+                    self.on_poll_success();
+                    match chain_tip {
+                        ChainTip::Common => log::debug!("No new best tip found"),
+
+                        ChainTip::Better(new_best) if !blocks_connected => log::error!(
+                            "Could not bring the listeners up to the new tip {}. Keeping the current one",
+                            new_best.header.block_hash()
+                        ),
+                        ChainTip::Better(new_best) => {
+                            log::debug!("Updating best tip: {}", new_best.header.block_hash());
+                            self.last_known_block_header = new_best;
+                            self.dbm
+                                .lock()
+                                .unwrap()
+                                .store_last_known_block(&new_best.header.block_hash())
+                                .unwrap();
+                        }
+                        ChainTip::Worse(worse) => {
+                            // This would happen both if a block has less chainwork than the previous one, or if it has the same chainwork
+                            // but it forks from the parent. In both cases, it'll be detected as a reorg once (if) the new chain grows past
+                            // the current tip.
+                            log::warn!("Worse tip found: {:?}", worse.header.block_hash());
+
+                            if worse.chainwork == self.last_known_block_header.chainwork {
+                                log::warn!("New tip has the same work as the previous one")
+                            } else {
+                                log::warn!("New tip has less work than the previous one")
+                            }
+                        }
+                    }
+                    return;
+                }
+                Err(e) if e.kind() == BlockSourceErrorKind::Transient => {
+                    log::error!(
+                        "Connection lost with source {}. Trying next configured source",
+                        source
+                    );
                 }
-            },
-            // FIXME: This may need finer catching
-            Err(_) => log::error!("Connection lost with bitcoind"),
-        };
+                Err(e) => {
+                    log::error!("Cannot validate the chain tip: {:?}", e.into_inner());
+                    self.on_poll_success();
+                    return;
+                }
+            }
+        }
+        self.on_poll_failure();
     }
 
-    /// Monitors `bitcoind` polling the best chain tip every [polling_delta](Self::polling_delta).
+    /// Resets the backoff and, the first time this is called after one or more failed polls,
+    /// logs the reconnection so it doesn't get lost among the per-poll debug logs.
+    fn on_poll_success(&mut self) {
+        if !self.healthy {
+            self.healthy = true;
+            log::warn!("Reconnected to bitcoind, resuming normal polling");
+        }
+        self.next_sleep = self.polling_delta;
+    }
+
+    /// Doubles [next_sleep](Self::next_sleep), capped at [MAX_BACKOFF](Self::MAX_BACKOFF), and,
+    /// the first time this is called after a successful poll, logs the disconnection once
+    /// rather than flooding the log on every subsequent retry.
+    fn on_poll_failure(&mut self) {
+        if self.healthy {
+            self.healthy = false;
+            log::error!("Lost connection with bitcoind. Backing off and retrying");
+        }
+        self.next_sleep = std::cmp::min(self.next_sleep * 2, Self::MAX_BACKOFF);
+    }
+
+    /// Monitors `bitcoind` polling the best chain tip every [polling_delta](Self::polling_delta)
+    /// while healthy, backing off exponentially while it isn't (see [on_poll_failure](Self::on_poll_failure)).
     pub async fn monitor_chain(&mut self) {
+        self.init().await;
         loop {
-            self.poll_best_tip().await;
-            // Sleep for self.polling_delta seconds or shutdown if the signal is received.
-            if timeout(self.polling_delta, self.shutdown_signal.clone())
+            // Sleep for self.next_sleep seconds or shutdown if the signal is received.
+            if timeout(self.next_sleep, self.shutdown_signal.clone())
                 .await
                 .is_ok()
             {
                 log::debug!("Received shutting down signal. Shutting down");
                 break;
             }
+
+            let was_healthy = self.healthy;
+            self.poll_best_tip().await;
+            if self.healthy && !was_healthy {
+                // Just reconnected: re-validate right away instead of waiting out the interval
+                // that was still inflated by the backoff.
+                self.poll_best_tip().await;
+            }
         }
     }
 }
@@ -116,14 +273,14 @@ mod tests {
     use std::collections::HashSet;
     use std::iter::FromIterator;
 
-    use bitcoin::network::constants::Network;
     use bitcoin::BlockHash;
-    use lightning_block_sync::{poll::ChainPoller, SpvClient, UnboundedCache};
+    use lightning_block_sync::{AsyncBlockSourceResult, BlockData, BlockHeaderData, BlockSourceError, UnboundedCache};
 
     use crate::test_utils::{Blockchain, START_HEIGHT};
 
     pub(crate) struct DummyListener {
         pub connected_blocks: RefCell<HashSet<BlockHash>>,
+        pub connected_calls: RefCell<u32>,
         pub disconnected_blocks: RefCell<HashSet<BlockHash>>,
     }
 
@@ -131,6 +288,7 @@ mod tests {
         fn new() -> Self {
             Self {
                 connected_blocks: RefCell::new(HashSet::new()),
+                connected_calls: RefCell::new(0),
                 disconnected_blocks: RefCell::new(HashSet::new()),
             }
         }
@@ -138,6 +296,7 @@ mod tests {
 
     impl chain::Listen for DummyListener {
         fn block_connected(&self, block: &bitcoin::Block, _: u32) {
+            *self.connected_calls.borrow_mut() += 1;
             self.connected_blocks
                 .borrow_mut()
                 .insert(block.block_hash());
@@ -152,18 +311,25 @@ mod tests {
 
     #[tokio::test]
     async fn test_poll_best_tip_common() {
-        let mut chain = Blockchain::default().with_height_and_txs(START_HEIGHT, None);
+        let chain = Blockchain::default().with_height_and_txs(START_HEIGHT, None);
         let tip = chain.tip();
 
         let dbm = Arc::new(Mutex::new(DBM::in_memory().unwrap()));
         let (_, shutdown_signal) = triggered::trigger();
         let listener = DummyListener::new();
-
-        let poller = ChainPoller::new(&mut chain, Network::Bitcoin);
         let cache = &mut UnboundedCache::new();
-        let spv_client = SpvClient::new(tip, poller, cache, &listener);
 
-        let mut cm = ChainMonitor::new(spv_client, tip, dbm, 1, shutdown_signal).await;
+        let mut cm = ChainMonitor::new(
+            vec![&chain],
+            Network::Bitcoin,
+            cache,
+            &listener,
+            tip,
+            dbm,
+            1,
+            shutdown_signal,
+        )
+        .await;
 
         // If there's no new block nothing gets connected nor disconnected
         cm.poll_best_tip().await;
@@ -173,19 +339,26 @@ mod tests {
 
     #[tokio::test]
     async fn test_poll_best_tip_better() {
-        let mut chain = Blockchain::default().with_height_and_txs(START_HEIGHT, None);
+        let chain = Blockchain::default().with_height_and_txs(START_HEIGHT, None);
         let new_tip = chain.tip();
         let old_tip = chain.at_height(START_HEIGHT - 1);
 
         let dbm = Arc::new(Mutex::new(DBM::in_memory().unwrap()));
         let (_, shutdown_signal) = triggered::trigger();
         let listener = DummyListener::new();
-
-        let poller = ChainPoller::new(&mut chain, Network::Bitcoin);
         let cache = &mut UnboundedCache::new();
-        let spv_client = SpvClient::new(old_tip, poller, cache, &listener);
 
-        let mut cm = ChainMonitor::new(spv_client, old_tip, dbm, 1, shutdown_signal).await;
+        let mut cm = ChainMonitor::new(
+            vec![&chain],
+            Network::Bitcoin,
+            cache,
+            &listener,
+            old_tip,
+            dbm,
+            1,
+            shutdown_signal,
+        )
+        .await;
 
         // If a new (best) block gets mined, it should be connected
         cm.poll_best_tip().await;
@@ -210,12 +383,19 @@ mod tests {
         let dbm = Arc::new(Mutex::new(DBM::in_memory().unwrap()));
         let (_, shutdown_signal) = triggered::trigger();
         let listener = DummyListener::new();
-
-        let poller = ChainPoller::new(&mut chain, Network::Bitcoin);
         let cache = &mut UnboundedCache::new();
-        let spv_client = SpvClient::new(best_tip, poller, cache, &listener);
 
-        let mut cm = ChainMonitor::new(spv_client, best_tip, dbm, 1, shutdown_signal).await;
+        let mut cm = ChainMonitor::new(
+            vec![&chain],
+            Network::Bitcoin,
+            cache,
+            &listener,
+            best_tip,
+            dbm,
+            1,
+            shutdown_signal,
+        )
+        .await;
 
         // If a new (worse, just one) block gets mined, nothing gets connected nor disconnected
         cm.poll_best_tip().await;
@@ -243,12 +423,19 @@ mod tests {
         let dbm = Arc::new(Mutex::new(DBM::in_memory().unwrap()));
         let (_, shutdown_signal) = triggered::trigger();
         let listener = DummyListener::new();
-
-        let poller = ChainPoller::new(&mut chain, Network::Bitcoin);
         let cache = &mut UnboundedCache::new();
-        let spv_client = SpvClient::new(old_best, poller, cache, &listener);
 
-        let mut cm = ChainMonitor::new(spv_client, old_best, dbm, 1, shutdown_signal).await;
+        let mut cm = ChainMonitor::new(
+            vec![&chain],
+            Network::Bitcoin,
+            cache,
+            &listener,
+            old_best,
+            dbm,
+            1,
+            shutdown_signal,
+        )
+        .await;
 
         // If a a reorg is found (tip is disconnected and a new best is found), both data should be connected and disconnected
         cm.poll_best_tip().await;
@@ -263,4 +450,425 @@ mod tests {
             HashSet::from_iter([old_best.deref().header.block_hash()])
         );
     }
+
+    #[tokio::test]
+    async fn test_init_catches_up_missed_blocks() {
+        // Simulates the tower being offline for a few blocks: last_known_block_header is several
+        // blocks behind the chain tip, so init should deliver all the blocks in between, not just
+        // the new tip.
+        let chain = Blockchain::default().with_height_and_txs(START_HEIGHT, None);
+        let old_tip = chain.at_height(START_HEIGHT - 3);
+        let missed_blocks = ((START_HEIGHT - 2)..=START_HEIGHT)
+            .map(|h| chain.at_height(h).deref().header.block_hash())
+            .collect::<HashSet<BlockHash>>();
+        let new_tip = chain.tip();
+
+        let dbm = Arc::new(Mutex::new(DBM::in_memory().unwrap()));
+        let (_, shutdown_signal) = triggered::trigger();
+        let listener = DummyListener::new();
+        let cache = &mut UnboundedCache::new();
+
+        let mut cm = ChainMonitor::new(
+            vec![&chain],
+            Network::Bitcoin,
+            cache,
+            &listener,
+            old_tip,
+            dbm,
+            1,
+            shutdown_signal,
+        )
+        .await;
+
+        // Before init, nothing has been delivered to the listener yet.
+        assert!(listener.connected_blocks.borrow().is_empty());
+
+        cm.init().await;
+
+        assert_eq!(cm.last_known_block_header, new_tip);
+        assert_eq!(
+            cm.dbm.lock().unwrap().load_last_known_block().unwrap(),
+            new_tip.deref().header.block_hash()
+        );
+        assert_eq!(*listener.connected_blocks.borrow(), missed_blocks);
+        assert!(listener.disconnected_blocks.borrow().is_empty());
+    }
+
+    /// A [BlockSource] that always fails with a transient (connection-level) error, used to
+    /// simulate an unreachable `bitcoind` in the failover and backoff tests below.
+    struct UnreachableBlockSource;
+
+    impl BlockSource for UnreachableBlockSource {
+        fn get_header<'a>(
+            &'a self,
+            _header_hash: &'a BlockHash,
+            _height_hint: Option<u32>,
+        ) -> AsyncBlockSourceResult<'a, BlockHeaderData> {
+            Box::pin(
+                async move { Err(BlockSourceError::transient("could not connect to bitcoind")) },
+            )
+        }
+
+        fn get_block<'a>(
+            &'a self,
+            _header_hash: &'a BlockHash,
+        ) -> AsyncBlockSourceResult<'a, BlockData> {
+            Box::pin(
+                async move { Err(BlockSourceError::transient("could not connect to bitcoind")) },
+            )
+        }
+
+        fn get_best_block<'a>(&'a self) -> AsyncBlockSourceResult<'a, (BlockHash, Option<u32>)> {
+            Box::pin(
+                async move { Err(BlockSourceError::transient("could not connect to bitcoind")) },
+            )
+        }
+    }
+
+    #[tokio::test]
+    async fn test_poll_best_tip_switches_source_on_error() {
+        // If the primary source is unreachable, ChainMonitor should fall back to the next
+        // configured one instead of giving up on the poll.
+        let primary = UnreachableBlockSource;
+        let chain = Blockchain::default().with_height_and_txs(START_HEIGHT, None);
+        let new_tip = chain.tip();
+        let old_tip = chain.at_height(START_HEIGHT - 1);
+
+        let dbm = Arc::new(Mutex::new(DBM::in_memory().unwrap()));
+        let (_, shutdown_signal) = triggered::trigger();
+        let listener = DummyListener::new();
+        let cache = &mut UnboundedCache::new();
+
+        let mut cm = ChainMonitor::new(
+            vec![&primary, &chain],
+            Network::Bitcoin,
+            cache,
+            &listener,
+            old_tip,
+            dbm,
+            1,
+            shutdown_signal,
+        )
+        .await;
+
+        assert_eq!(cm.active_source, 0);
+        cm.poll_best_tip().await;
+
+        // The primary source failed, so the fallback should have been tried (and succeeded).
+        assert_eq!(cm.active_source, 1);
+        assert_eq!(cm.last_known_block_header, new_tip);
+        assert!(listener
+            .connected_blocks
+            .borrow()
+            .contains(&new_tip.deref().header.block_hash()));
+    }
+
+    /// A [BlockSource] that always fails with a persistent (validation-level) error, counting
+    /// how many times it was asked for the best block. Used to check that a validation error
+    /// is tried once and doesn't get retried against the same source, nor falls through to the
+    /// next configured one.
+    struct InvalidBlockSource {
+        calls: RefCell<u32>,
+    }
+
+    impl InvalidBlockSource {
+        fn new() -> Self {
+            Self {
+                calls: RefCell::new(0),
+            }
+        }
+    }
+
+    impl BlockSource for InvalidBlockSource {
+        fn get_header<'a>(
+            &'a self,
+            _header_hash: &'a BlockHash,
+            _height_hint: Option<u32>,
+        ) -> AsyncBlockSourceResult<'a, BlockHeaderData> {
+            Box::pin(async move { Err(BlockSourceError::persistent("invalid chain data")) })
+        }
+
+        fn get_block<'a>(
+            &'a self,
+            _header_hash: &'a BlockHash,
+        ) -> AsyncBlockSourceResult<'a, BlockData> {
+            Box::pin(async move { Err(BlockSourceError::persistent("invalid chain data")) })
+        }
+
+        fn get_best_block<'a>(&'a self) -> AsyncBlockSourceResult<'a, (BlockHash, Option<u32>)> {
+            *self.calls.borrow_mut() += 1;
+            Box::pin(async move { Err(BlockSourceError::persistent("invalid chain data")) })
+        }
+    }
+
+    #[tokio::test]
+    async fn test_poll_best_tip_does_not_retry_on_validation_error() {
+        // A validation error should be logged and leave the current tip untouched, without being
+        // retried against the same source or falling through to the next configured one.
+        let invalid = InvalidBlockSource::new();
+        let chain = Blockchain::default().with_height_and_txs(START_HEIGHT, None);
+        let tip = chain.tip();
+
+        let dbm = Arc::new(Mutex::new(DBM::in_memory().unwrap()));
+        let (_, shutdown_signal) = triggered::trigger();
+        let listener = DummyListener::new();
+        let cache = &mut UnboundedCache::new();
+
+        let mut cm = ChainMonitor::new(
+            vec![&invalid, &chain],
+            Network::Bitcoin,
+            cache,
+            &listener,
+            tip,
+            dbm,
+            1,
+            shutdown_signal,
+        )
+        .await;
+
+        cm.poll_best_tip().await;
+
+        // The invalid source was asked for the best block exactly once...
+        assert_eq!(*invalid.calls.borrow(), 1);
+        // ...the monitor didn't fall through to the fallback source...
+        assert_eq!(cm.active_source, 0);
+        // ...and the current tip was left untouched.
+        assert_eq!(cm.last_known_block_header, tip);
+        assert!(listener.connected_blocks.borrow().is_empty());
+        assert!(listener.disconnected_blocks.borrow().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_poll_best_tip_backs_off_on_repeated_failures() {
+        // With a single, permanently unreachable source, every poll should fail, flip `healthy`
+        // to false and double the sleep (up to the cap).
+        let source = UnreachableBlockSource;
+
+        let dbm = Arc::new(Mutex::new(DBM::in_memory().unwrap()));
+        let (_, shutdown_signal) = triggered::trigger();
+        let listener = DummyListener::new();
+        let cache = &mut UnboundedCache::new();
+        let tip = Blockchain::default()
+            .with_height_and_txs(START_HEIGHT, None)
+            .tip();
+
+        let mut cm = ChainMonitor::new(
+            vec![&source],
+            Network::Bitcoin,
+            cache,
+            &listener,
+            tip,
+            dbm,
+            1,
+            shutdown_signal,
+        )
+        .await;
+
+        assert!(cm.is_healthy());
+        assert_eq!(cm.next_sleep, time::Duration::from_secs(1));
+
+        cm.poll_best_tip().await;
+        assert!(!cm.is_healthy());
+        assert_eq!(cm.next_sleep, time::Duration::from_secs(2));
+
+        cm.poll_best_tip().await;
+        assert!(!cm.is_healthy());
+        assert_eq!(cm.next_sleep, time::Duration::from_secs(4));
+    }
+
+    /// A [BlockSource] that fails with a transient error for its first `fails_left` calls and
+    /// then delegates to the wrapped source, used to simulate a `bitcoind` that comes back after
+    /// being down for a while.
+    struct FlakyBlockSource<'a> {
+        inner: &'a mut Blockchain,
+        fails_left: RefCell<u32>,
+    }
+
+    impl<'a> FlakyBlockSource<'a> {
+        fn new(inner: &'a mut Blockchain, fails: u32) -> Self {
+            Self {
+                inner,
+                fails_left: RefCell::new(fails),
+            }
+        }
+
+        fn take_failure(&self) -> bool {
+            let mut fails_left = self.fails_left.borrow_mut();
+            if *fails_left > 0 {
+                *fails_left -= 1;
+                true
+            } else {
+                false
+            }
+        }
+    }
+
+    impl<'a> BlockSource for FlakyBlockSource<'a> {
+        fn get_header<'b>(
+            &'b self,
+            header_hash: &'b BlockHash,
+            height_hint: Option<u32>,
+        ) -> AsyncBlockSourceResult<'b, BlockHeaderData> {
+            if self.take_failure() {
+                return Box::pin(
+                    async move { Err(BlockSourceError::transient("could not connect to bitcoind")) },
+                );
+            }
+            self.inner.get_header(header_hash, height_hint)
+        }
+
+        fn get_block<'b>(&'b self, header_hash: &'b BlockHash) -> AsyncBlockSourceResult<'b, BlockData> {
+            if self.take_failure() {
+                return Box::pin(
+                    async move { Err(BlockSourceError::transient("could not connect to bitcoind")) },
+                );
+            }
+            self.inner.get_block(header_hash)
+        }
+
+        fn get_best_block<'b>(&'b self) -> AsyncBlockSourceResult<'b, (BlockHash, Option<u32>)> {
+            if self.take_failure() {
+                return Box::pin(
+                    async move { Err(BlockSourceError::transient("could not connect to bitcoind")) },
+                );
+            }
+            self.inner.get_best_block()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_poll_best_tip_recovers_after_failure() {
+        // A source that fails a couple of times and then starts answering again should bring
+        // the monitor back to healthy and reset the backoff, keeping the tip it already had (no
+        // new block is mined in between).
+        let mut chain = Blockchain::default().with_height_and_txs(START_HEIGHT, None);
+        let tip = chain.tip();
+        let flaky = FlakyBlockSource::new(&mut chain, 2);
+
+        let dbm = Arc::new(Mutex::new(DBM::in_memory().unwrap()));
+        let (_, shutdown_signal) = triggered::trigger();
+        let listener = DummyListener::new();
+        let cache = &mut UnboundedCache::new();
+
+        let mut cm = ChainMonitor::new(
+            vec![&flaky],
+            Network::Bitcoin,
+            cache,
+            &listener,
+            tip,
+            dbm,
+            1,
+            shutdown_signal,
+        )
+        .await;
+
+        cm.poll_best_tip().await;
+        assert!(!cm.is_healthy());
+        assert_eq!(cm.next_sleep, time::Duration::from_secs(2));
+
+        cm.poll_best_tip().await;
+        assert!(!cm.is_healthy());
+        assert_eq!(cm.next_sleep, time::Duration::from_secs(4));
+
+        // The third poll reaches the (now working) source again, so the monitor should recover.
+        cm.poll_best_tip().await;
+        assert!(cm.is_healthy());
+        assert_eq!(cm.next_sleep, time::Duration::from_secs(1));
+        assert_eq!(cm.last_known_block_header, tip);
+    }
+
+    /// A [BlockSource] that delegates to the wrapped source for its first `calls_left` calls to
+    /// [get_best_block](BlockSource::get_best_block) and fails with a transient error forever
+    /// after, used to simulate a primary `bitcoind` that goes down partway through polling.
+    struct DyingBlockSource<'a> {
+        inner: &'a Blockchain,
+        calls_left: RefCell<u32>,
+    }
+
+    impl<'a> DyingBlockSource<'a> {
+        fn new(inner: &'a Blockchain, calls: u32) -> Self {
+            Self {
+                inner,
+                calls_left: RefCell::new(calls),
+            }
+        }
+
+        fn still_alive(&self) -> bool {
+            let mut calls_left = self.calls_left.borrow_mut();
+            if *calls_left > 0 {
+                *calls_left -= 1;
+                true
+            } else {
+                false
+            }
+        }
+    }
+
+    impl<'a> BlockSource for DyingBlockSource<'a> {
+        fn get_header<'b>(
+            &'b self,
+            header_hash: &'b BlockHash,
+            height_hint: Option<u32>,
+        ) -> AsyncBlockSourceResult<'b, BlockHeaderData> {
+            self.inner.get_header(header_hash, height_hint)
+        }
+
+        fn get_block<'b>(&'b self, header_hash: &'b BlockHash) -> AsyncBlockSourceResult<'b, BlockData> {
+            self.inner.get_block(header_hash)
+        }
+
+        fn get_best_block<'b>(&'b self) -> AsyncBlockSourceResult<'b, (BlockHash, Option<u32>)> {
+            if self.still_alive() {
+                self.inner.get_best_block()
+            } else {
+                Box::pin(async move {
+                    Err(BlockSourceError::transient("could not connect to bitcoind"))
+                })
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_poll_best_tip_does_not_redeliver_blocks_after_source_switch() {
+        // The primary answers one successful poll, delivering a few missed blocks, before going
+        // down. The fallback, which points at the very same chain but has never been probed
+        // until then, must pick up from the tip the tower already reached instead of comparing
+        // against a tip of its own and replaying the blocks primary already delivered.
+        let chain = Blockchain::default().with_height_and_txs(START_HEIGHT, None);
+        let old_tip = chain.at_height(START_HEIGHT - 3);
+        let new_tip = chain.tip();
+
+        let primary = DyingBlockSource::new(&chain, 1);
+
+        let dbm = Arc::new(Mutex::new(DBM::in_memory().unwrap()));
+        let (_, shutdown_signal) = triggered::trigger();
+        let listener = DummyListener::new();
+        let cache = &mut UnboundedCache::new();
+
+        let mut cm = ChainMonitor::new(
+            vec![&primary, &chain],
+            Network::Bitcoin,
+            cache,
+            &listener,
+            old_tip,
+            dbm,
+            1,
+            shutdown_signal,
+        )
+        .await;
+
+        // First poll: primary is still alive and catches the tower up to the current tip.
+        cm.poll_best_tip().await;
+        assert_eq!(cm.active_source, 0);
+        assert_eq!(cm.last_known_block_header, new_tip);
+        let connected_after_primary = *listener.connected_calls.borrow();
+        assert_eq!(connected_after_primary, 3);
+
+        // Second poll: primary is now down, so the fallback (pointing at the same chain) takes
+        // over. It must not redeliver the blocks primary already connected.
+        cm.poll_best_tip().await;
+        assert_eq!(cm.active_source, 1);
+        assert_eq!(cm.last_known_block_header, new_tip);
+        assert_eq!(*listener.connected_calls.borrow(), connected_after_primary);
+    }
 }